@@ -47,6 +47,40 @@ pub trait CofactorGroup:
     /// - `false` if `self` has non-zero torsion component and is not in the prime-order
     ///   subgroup.
     fn is_torsion_free(&self) -> Choice;
+
+    /// Determines if this element is contained in the prime-order subgroup.
+    ///
+    /// This is a more discoverable name for [`Self::is_torsion_free`], which this method
+    /// calls by default.
+    fn is_in_correct_subgroup(&self) -> Choice {
+        self.is_torsion_free()
+    }
+
+    /// The inverse of the cofactor, modulo the prime subgroup order $r$: $h^{-1} \bmod r$.
+    const COFACTOR_INV: Self::Scalar;
+
+    /// Multiplies a prime-order-subgroup element by the inverse of the cofactor modulo
+    /// the subgroup order.
+    ///
+    /// This is only a true inverse of [`Self::clear_cofactor`] for implementations whose
+    /// cofactor-clearing multiplier `k` is exactly `1`: for those implementations, and any
+    /// `x: Self::Subgroup`, `Into::<Self>::into(Self::mul_by_cofactor_inv(&x))
+    /// .clear_cofactor() == x`, which is useful for "un-clearing" a subgroup element back
+    /// into `Self` without leaving the subgroup. Implementations of [`Self::clear_cofactor`]
+    /// that use a `k` other than `1` (see its docs) must still provide
+    /// [`Self::COFACTOR_INV`], but this method does not invert their
+    /// [`Self::clear_cofactor`], since no fixed multiplier inverts a `k`-dependent
+    /// cofactor-clearing map for every input.
+    ///
+    /// Unlike a hypothetical `&self` method on `CofactorGroup`, this takes an element
+    /// already known to be in [`Self::Subgroup`], so the multiplication is guaranteed to
+    /// land back in the subgroup and never needs to fail.
+    fn mul_by_cofactor_inv(subgroup_element: &Self::Subgroup) -> Self::Subgroup
+    where
+        Self::Subgroup: core::ops::Mul<Self::Scalar, Output = Self::Subgroup>,
+    {
+        *subgroup_element * Self::COFACTOR_INV
+    }
 }
 
 /// Efficient representation of an elliptic curve point guaranteed to be
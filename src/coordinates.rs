@@ -6,9 +6,21 @@
 //! the scope of the generic curve parameter; this ensures that the code can only be used
 //! with curve implementations that explicitly expose their use of a specific curve model.
 
-use subtle::{Choice, ConditionallySelectable, CtOption};
+use ff::{Field, PrimeField};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::CurveAffine;
+use crate::hash_to_curve::{map_to_curve_elligator2, map_to_curve_simple_swu};
+use crate::{CurveAffine, Identity};
+
+/// Selects between `y` and `-y` so that the least-significant bit of the result's
+/// canonical encoding (its "sign") matches `sign`.
+///
+/// Used by the `from_x_coordinate`/`from_y_coordinate` methods below to pick a
+/// deterministic square root out of the two available for a nonzero element.
+fn select_sign<F: PrimeField>(y: F, sign: Choice) -> F {
+    let y_sign = Choice::from(y.to_repr().as_ref()[0] & 1);
+    F::conditional_select(&(-y), &y, y_sign.ct_eq(&sign))
+}
 
 //
 // Twisted Edwards curve
@@ -18,7 +30,7 @@ use crate::CurveAffine;
 /// $a \cdot x^2 + y^2 = 1 + d \cdot x^2 \cdot y^2$.
 pub trait TwistedEdwardsPoint: CurveAffine + Default + ConditionallySelectable {
     /// Field element type used in the curve equation.
-    type Base: Copy + ConditionallySelectable;
+    type Base: PrimeField;
 
     /// The parameter $a$ in the twisted Edwards curve equation.
     ///
@@ -28,6 +40,10 @@ pub trait TwistedEdwardsPoint: CurveAffine + Default + ConditionallySelectable {
     /// The parameter $d$ in the twisted Edwards curve equation.
     const D: Self::Base;
 
+    /// A non-square element of [`Self::Base`], used by the default [`Self::map_to_curve`]
+    /// implementation.
+    const Z: Self::Base;
+
     /// Obtains a point given $(x, y)$, failing if it is not on the curve.
     fn from_bare_coordinates(x: Self::Base, y: Self::Base) -> CtOption<Self>;
 
@@ -39,6 +55,45 @@ pub trait TwistedEdwardsPoint: CurveAffine + Default + ConditionallySelectable {
     /// For twisted Edwards curves, the identity has valid coordinates on the curve, so
     /// this method is infallible.
     fn coordinates(&self) -> TwistedEdwardsCoordinates<Self>;
+
+    /// Maps a base field element to a point on the curve, via the Elligator 2 method of
+    /// [RFC 9380 §6.7.1] applied to the birationally-equivalent Montgomery curve and
+    /// converted back with the standard rational map.
+    ///
+    /// The resulting point is not necessarily in the prime-order subgroup; see
+    /// [`crate::hash_to_curve`] for the full `hash_to_curve`/`encode_to_curve`
+    /// construction.
+    ///
+    /// [RFC 9380 §6.7.1]: https://www.rfc-editor.org/rfc/rfc9380#section-6.7.1
+    fn map_to_curve(field_u: Self::Base) -> Self {
+        // Montgomery parameters of the curve birationally equivalent to this one:
+        // A = 2(a+d)/(a-d), B = 4/(a-d).
+        let inv_a_minus_d = (Self::A - Self::D).invert().unwrap_or(Self::Base::ZERO);
+        let mont_a = (Self::A + Self::D).double() * inv_a_minus_d;
+        let mont_b = Self::Base::ONE.double().double() * inv_a_minus_d;
+
+        let (mont_u, mont_v) = map_to_curve_elligator2(field_u, mont_a, mont_b, Self::Z);
+
+        // Montgomery (u, v) -> Edwards (x, y): x = u/v, y = (u-1)/(u+1).
+        let x = mont_u * mont_v.invert().unwrap_or(Self::Base::ZERO);
+        let y = (mont_u - Self::Base::ONE) * (mont_u + Self::Base::ONE).invert().unwrap_or(Self::Base::ZERO);
+
+        Self::from_bare_coordinates(x, y).unwrap()
+    }
+
+    /// Recovers a point from its $y$-coordinate and a sign bit for $x$, by solving
+    /// $x^2 = (y^2 - 1)/(d y^2 - a)$ and selecting the root whose sign (the
+    /// least-significant bit of its canonical encoding) matches `sign`.
+    ///
+    /// Returns `None` if $y$ does not correspond to a point on the curve.
+    fn from_y_coordinate(y: Self::Base, sign: Choice) -> CtOption<Self> {
+        let y2 = y.square();
+        let rhs = (y2 - Self::Base::ONE) * (Self::D * y2 - Self::A).invert().unwrap_or(Self::Base::ZERO);
+        rhs.sqrt().and_then(|x0| {
+            let x = select_sign(x0, sign);
+            Self::from_bare_coordinates(x, y)
+        })
+    }
 }
 
 /// The affine coordinates for a [`TwistedEdwardsPoint`].
@@ -87,7 +142,7 @@ impl<P: TwistedEdwardsPoint> ConditionallySelectable for TwistedEdwardsCoordinat
 /// $A ≠ ±2$ and $B ≠ 0$.
 pub trait MontgomeryPoint: CurveAffine + Default + ConditionallySelectable {
     /// Field element type used in the curve equation.
-    type Base: Copy + ConditionallySelectable;
+    type Base: PrimeField;
 
     /// The parameter $A$ in the Montgomery curve equation.
     const A: Self::Base;
@@ -95,6 +150,10 @@ pub trait MontgomeryPoint: CurveAffine + Default + ConditionallySelectable {
     /// The parameter $B$ in the Montgomery curve equation.
     const B: Self::Base;
 
+    /// A non-square element of [`Self::Base`], used by the default [`Self::map_to_curve`]
+    /// implementation.
+    const Z: Self::Base;
+
     /// Obtains a point given $(u, v)$, failing if it is not on the curve.
     fn from_bare_coordinates(u: Self::Base, v: Self::Base) -> CtOption<Self>;
 
@@ -105,9 +164,35 @@ pub trait MontgomeryPoint: CurveAffine + Default + ConditionallySelectable {
     ///
     /// Returns `None` if this is the identity.
     fn coordinates(&self) -> CtOption<MontgomeryCoordinates<Self>>;
+
+    /// Maps a base field element to a point on the curve, using the Elligator 2 method of
+    /// [RFC 9380 §6.7.1].
+    ///
+    /// The resulting point is not necessarily in the prime-order subgroup; see
+    /// [`crate::hash_to_curve`] for the full `hash_to_curve`/`encode_to_curve`
+    /// construction.
+    ///
+    /// [RFC 9380 §6.7.1]: https://www.rfc-editor.org/rfc/rfc9380#section-6.7.1
+    fn map_to_curve(field_u: Self::Base) -> Self {
+        let (u, v) = map_to_curve_elligator2(field_u, Self::A, Self::B, Self::Z);
+        Self::from_bare_coordinates(u, v).unwrap()
+    }
+
+    /// Recovers a point from its $u$-coordinate and a sign bit for $v$, by solving
+    /// $Bv^2 = u^3 + Au^2 + u$ and selecting the root whose sign (the least-significant
+    /// bit of its canonical encoding) matches `sign`.
+    ///
+    /// Returns `None` if $u$ does not correspond to a point on the curve.
+    fn from_x_coordinate(u: Self::Base, sign: Choice) -> CtOption<Self> {
+        let rhs = ((u.square() + Self::A * u) * u + u) * Self::B.invert().unwrap_or(Self::Base::ZERO);
+        rhs.sqrt().and_then(|v0| {
+            let v = select_sign(v0, sign);
+            Self::from_bare_coordinates(u, v)
+        })
+    }
 }
 
-/// The affine coordinates for a [`MontgomeryCoordinates`].
+/// The affine coordinates for a [`MontgomeryPoint`].
 #[derive(Clone, Copy, Debug, Default)]
 pub struct MontgomeryCoordinates<P: MontgomeryPoint> {
     u: P::Base,
@@ -142,6 +227,61 @@ impl<P: MontgomeryPoint> ConditionallySelectable for MontgomeryCoordinates<P> {
     }
 }
 
+//
+// Birational equivalence between Montgomery and twisted Edwards curves
+//
+
+/// Converts a twisted Edwards point to its birationally-equivalent Montgomery point, via
+/// $u = (1+y)/(1-y)$, $v = u/x$.
+///
+/// The Edwards identity $(0, 1)$ has no corresponding affine Montgomery point (it maps to
+/// the point at infinity); this is handled as a constant-time special case.
+pub fn to_montgomery<E, M>(point: &E) -> M
+where
+    E: TwistedEdwardsPoint,
+    M: MontgomeryPoint<Base = E::Base> + Identity,
+{
+    let coords = point.coordinates();
+    let x = coords.x();
+    let y = coords.y();
+
+    let is_identity = y.ct_eq(&E::Base::ONE);
+
+    let u = (E::Base::ONE + y) * (E::Base::ONE - y).invert().unwrap_or(E::Base::ZERO);
+    let v = u * x.invert().unwrap_or(E::Base::ZERO);
+
+    // By construction, (u, v) always satisfies $Bv^2 = u^3 + Au^2 + u$: either it is a
+    // genuine point of the curve, or `is_identity` is set and (u, v) = (0, 0), which
+    // satisfies the equation trivially.
+    let finite = M::from_bare_coordinates(u, v).unwrap();
+    M::conditional_select(&finite, &M::identity(), is_identity)
+}
+
+/// Converts a Montgomery point to its birationally-equivalent twisted Edwards point, via
+/// $x = u/v$, $y = (u-1)/(u+1)$.
+///
+/// The Montgomery identity (the point at infinity) has no affine $(u, v)$ coordinates;
+/// this is handled as a constant-time special case, mapping it to the Edwards identity
+/// $(0, 1)$.
+pub fn from_montgomery<M, E>(point: &M) -> E
+where
+    M: MontgomeryPoint,
+    E: TwistedEdwardsPoint<Base = M::Base>,
+{
+    let coords = point.coordinates();
+    let is_identity = !coords.is_some();
+    let coords = coords.unwrap_or_else(MontgomeryCoordinates::default);
+    let u = coords.u();
+    let v = coords.v();
+
+    let x = u * v.invert().unwrap_or(M::Base::ZERO);
+    let y = (u - M::Base::ONE) * (u + M::Base::ONE).invert().unwrap_or(M::Base::ZERO);
+
+    let finite = E::from_bare_coordinates(x, y).unwrap();
+    let identity = E::from_bare_coordinates(E::Base::ZERO, E::Base::ONE).unwrap();
+    E::conditional_select(&finite, &identity, is_identity)
+}
+
 //
 // Short Weierstrass curve
 //
@@ -150,7 +290,7 @@ impl<P: MontgomeryPoint> ConditionallySelectable for MontgomeryCoordinates<P> {
 /// $y^2 = x^3 + a \cdot x + b$.
 pub trait ShortWeierstrassPoint: CurveAffine + Default + ConditionallySelectable {
     /// Field element type used in the curve equation.
-    type Base: Copy + ConditionallySelectable;
+    type Base: PrimeField;
 
     /// The parameter $a$ in the short Weierstrass curve equation.
     const A: Self::Base;
@@ -158,6 +298,12 @@ pub trait ShortWeierstrassPoint: CurveAffine + Default + ConditionallySelectable
     /// The parameter $b$ in the short Weierstrass curve equation.
     const B: Self::Base;
 
+    /// A non-square element of [`Self::Base`], used by the default [`Self::map_to_curve`]
+    /// implementation. Required when $a \cdot b \neq 0$; curves for which $a \cdot b = 0$
+    /// must override `map_to_curve` with an isogeny-based mapping instead (RFC 9380
+    /// §6.6.3).
+    const Z: Self::Base;
+
     /// Obtains a point given $(x, y)$, failing if it is not on the curve.
     fn from_bare_coordinates(x: Self::Base, y: Self::Base) -> CtOption<Self>;
 
@@ -168,9 +314,35 @@ pub trait ShortWeierstrassPoint: CurveAffine + Default + ConditionallySelectable
     ///
     /// Returns `None` if this is the identity.
     fn coordinates(&self) -> CtOption<ShortWeierstrassCoordinates<Self>>;
+
+    /// Maps a base field element to a point on the curve, using the Simplified SWU
+    /// method of [RFC 9380 §6.6.2], valid whenever $a \cdot b \neq 0$.
+    ///
+    /// The resulting point is not necessarily in the prime-order subgroup; see
+    /// [`crate::hash_to_curve`] for the full `hash_to_curve`/`encode_to_curve`
+    /// construction. Curves with $a \cdot b = 0$ must override this method.
+    ///
+    /// [RFC 9380 §6.6.2]: https://www.rfc-editor.org/rfc/rfc9380#section-6.6.2
+    fn map_to_curve(u: Self::Base) -> Self {
+        let (x, y) = map_to_curve_simple_swu(u, Self::A, Self::B, Self::Z);
+        Self::from_bare_coordinates(x, y).unwrap()
+    }
+
+    /// Recovers a point from its $x$-coordinate and a sign bit for $y$, by solving
+    /// $y^2 = x^3 + ax + b$ and selecting the root whose sign (the least-significant bit
+    /// of its canonical encoding) matches `sign`.
+    ///
+    /// Returns `None` if $x$ does not correspond to a point on the curve.
+    fn from_x_coordinate(x: Self::Base, sign: Choice) -> CtOption<Self> {
+        let rhs = (x.square() + Self::A) * x + Self::B;
+        rhs.sqrt().and_then(|y0| {
+            let y = select_sign(y0, sign);
+            Self::from_bare_coordinates(x, y)
+        })
+    }
 }
 
-/// The affine coordinates for a [`ShortWeierstrassCoordinates`].
+/// The affine coordinates for a [`ShortWeierstrassPoint`].
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ShortWeierstrassCoordinates<P: ShortWeierstrassPoint> {
     x: P::Base,
@@ -204,3 +376,727 @@ impl<P: ShortWeierstrassPoint> ConditionallySelectable for ShortWeierstrassCoord
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    //! Exercises [`to_montgomery`]/[`from_montgomery`] against a concrete (insecure, toy)
+    //! twisted Edwards curve over $\mathbb{F}_{251}$: $x^2 + y^2 = 1 + 2x^2y^2$, whose
+    //! birationally-equivalent Montgomery curve is $247v^2 = u^3 + 245u^2 + u$.
+    use core::iter::{Product, Sum};
+    use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    use rand_core::RngCore;
+    use subtle::ConstantTimeEq;
+
+    use super::*;
+    use crate::{Curve, Group, GroupEncoding};
+
+    const MODULUS: u32 = 251;
+
+    fn mod_pow(mut base: u32, mut exp: u32) -> u32 {
+        base %= MODULUS;
+        let mut result = 1u32;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % MODULUS;
+            }
+            exp >>= 1;
+            base = base * base % MODULUS;
+        }
+        result
+    }
+
+    /// A toy prime field of order 251, used only to exercise the generic birational
+    /// conversion functions above against a concrete curve.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Fp251(u8);
+
+    impl Fp251 {
+        fn new(v: u32) -> Self {
+            Fp251((v % MODULUS) as u8)
+        }
+    }
+
+    impl ConstantTimeEq for Fp251 {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl ConditionallySelectable for Fp251 {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            Fp251(u8::conditional_select(&a.0, &b.0, choice))
+        }
+    }
+
+    impl Add for Fp251 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 + rhs.0 as u32)
+        }
+    }
+    impl Sub for Fp251 {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 + MODULUS - rhs.0 as u32)
+        }
+    }
+    impl Mul for Fp251 {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 * rhs.0 as u32)
+        }
+    }
+    impl Neg for Fp251 {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Fp251::new(MODULUS - self.0 as u32)
+        }
+    }
+    impl<'a> Add<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn add(self, rhs: &'a Fp251) -> Self {
+            self + *rhs
+        }
+    }
+    impl<'a> Sub<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn sub(self, rhs: &'a Fp251) -> Self {
+            self - *rhs
+        }
+    }
+    impl<'a> Mul<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn mul(self, rhs: &'a Fp251) -> Self {
+            self * *rhs
+        }
+    }
+    impl AddAssign for Fp251 {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl SubAssign for Fp251 {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl MulAssign for Fp251 {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+    impl<'a> AddAssign<&'a Fp251> for Fp251 {
+        fn add_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self + *rhs;
+        }
+    }
+    impl<'a> SubAssign<&'a Fp251> for Fp251 {
+        fn sub_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self - *rhs;
+        }
+    }
+    impl<'a> MulAssign<&'a Fp251> for Fp251 {
+        fn mul_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self * *rhs;
+        }
+    }
+    impl Sum for Fp251 {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ZERO, |a, b| a + b)
+        }
+    }
+    impl<'a> Sum<&'a Fp251> for Fp251 {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ZERO, |a, b| a + *b)
+        }
+    }
+    impl Product for Fp251 {
+        fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ONE, |a, b| a * b)
+        }
+    }
+    impl<'a> Product<&'a Fp251> for Fp251 {
+        fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ONE, |a, b| a * *b)
+        }
+    }
+
+    impl Field for Fp251 {
+        const ZERO: Self = Fp251(0);
+        const ONE: Self = Fp251(1);
+
+        fn random(mut rng: impl RngCore) -> Self {
+            Fp251::new(rng.next_u32())
+        }
+
+        fn square(&self) -> Self {
+            *self * *self
+        }
+
+        fn double(&self) -> Self {
+            *self + *self
+        }
+
+        fn invert(&self) -> CtOption<Self> {
+            let is_zero = self.ct_eq(&Fp251::ZERO);
+            let inv = Fp251::new(mod_pow(self.0 as u32, MODULUS - 2));
+            CtOption::new(inv, !is_zero)
+        }
+
+        fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+            let ratio = *num * div.invert().unwrap_or(Fp251::ZERO);
+            // 251 = 3 (mod 4), so sqrt(a) = a^((p+1)/4) whenever `a` is a square.
+            let candidate = Fp251::new(mod_pow(ratio.0 as u32, (MODULUS + 1) / 4));
+            let is_square = candidate.square().ct_eq(&ratio);
+            (is_square, candidate)
+        }
+    }
+
+    impl PrimeField for Fp251 {
+        type Repr = [u8; 1];
+
+        fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+            let in_range = Choice::from((repr[0] < MODULUS as u8) as u8);
+            CtOption::new(Fp251(repr[0]), in_range)
+        }
+
+        fn to_repr(&self) -> Self::Repr {
+            [self.0]
+        }
+
+        fn is_odd(&self) -> Choice {
+            Choice::from(self.0 & 1)
+        }
+
+        const MODULUS: &'static str = "251";
+        const NUM_BITS: u32 = 8;
+        const CAPACITY: u32 = 7;
+        const TWO_INV: Self = Fp251(126);
+        const MULTIPLICATIVE_GENERATOR: Self = Fp251(6);
+        const S: u32 = 1;
+        const ROOT_OF_UNITY: Self = Fp251(250);
+        const ROOT_OF_UNITY_INV: Self = Fp251(250);
+        const DELTA: Self = Fp251(36);
+    }
+
+    /// Scalar multiplication via double-and-add, shared by the toy curve types below.
+    ///
+    /// Not constant-time: this is test-only scaffolding, not a real curve implementation.
+    fn scalar_mul<P: Copy + Identity + Add<Output = P>>(base: P, scalar: &Fp251) -> P {
+        let bits = scalar.to_repr()[0];
+        let mut acc = P::identity();
+        let mut cur = base;
+        for i in 0..8 {
+            if (bits >> i) & 1 == 1 {
+                acc = acc + cur;
+            }
+            cur = cur + cur;
+        }
+        acc
+    }
+
+    /// An affine point on the toy twisted Edwards curve $x^2 + y^2 = 1 + 2x^2y^2$.
+    ///
+    /// Acts as both [`CurveAffine`] and its own [`Curve`]: this fixture only exists to
+    /// exercise [`to_montgomery`]/[`from_montgomery`], so there is no need for a separate
+    /// projective representation.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct EdPoint {
+        x: Fp251,
+        y: Fp251,
+    }
+
+    impl EdPoint {
+        fn is_on_curve(x: Fp251, y: Fp251) -> Choice {
+            let a = <EdPoint as super::TwistedEdwardsPoint>::A;
+            let d = <EdPoint as super::TwistedEdwardsPoint>::D;
+            let lhs = a * x.square() + y.square();
+            let rhs = Fp251::ONE + d * x.square() * y.square();
+            lhs.ct_eq(&rhs)
+        }
+    }
+
+    impl Default for EdPoint {
+        fn default() -> Self {
+            <EdPoint as Identity>::identity()
+        }
+    }
+
+    impl ConditionallySelectable for EdPoint {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            EdPoint {
+                x: Fp251::conditional_select(&a.x, &b.x, choice),
+                y: Fp251::conditional_select(&a.y, &b.y, choice),
+            }
+        }
+    }
+
+    impl Identity for EdPoint {
+        fn identity() -> Self {
+            EdPoint { x: Fp251::ZERO, y: Fp251::ONE }
+        }
+    }
+
+    impl Neg for EdPoint {
+        type Output = Self;
+        fn neg(self) -> Self {
+            EdPoint { x: -self.x, y: self.y }
+        }
+    }
+
+    impl Add for EdPoint {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            // Unified twisted Edwards addition law.
+            let a = <EdPoint as super::TwistedEdwardsPoint>::A;
+            let d = <EdPoint as super::TwistedEdwardsPoint>::D;
+            let cross = d * self.x * rhs.x * self.y * rhs.y;
+            let x = (self.x * rhs.y + self.y * rhs.x) * (Fp251::ONE + cross).invert().unwrap();
+            let y = (self.y * rhs.y - a * self.x * rhs.x) * (Fp251::ONE - cross).invert().unwrap();
+            EdPoint { x, y }
+        }
+    }
+    impl Sub for EdPoint {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            self + (-rhs)
+        }
+    }
+    impl<'a> Add<&'a EdPoint> for EdPoint {
+        type Output = Self;
+        fn add(self, rhs: &'a EdPoint) -> Self {
+            self + *rhs
+        }
+    }
+    impl<'a> Sub<&'a EdPoint> for EdPoint {
+        type Output = Self;
+        fn sub(self, rhs: &'a EdPoint) -> Self {
+            self - *rhs
+        }
+    }
+    impl AddAssign for EdPoint {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl SubAssign for EdPoint {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl<'a> AddAssign<&'a EdPoint> for EdPoint {
+        fn add_assign(&mut self, rhs: &'a EdPoint) {
+            *self = *self + *rhs;
+        }
+    }
+    impl<'a> SubAssign<&'a EdPoint> for EdPoint {
+        fn sub_assign(&mut self, rhs: &'a EdPoint) {
+            *self = *self - *rhs;
+        }
+    }
+    impl Sum for EdPoint {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(<EdPoint as Identity>::identity(), |a, b| a + b)
+        }
+    }
+    impl<'a> Sum<&'a EdPoint> for EdPoint {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(<EdPoint as Identity>::identity(), |a, b| a + *b)
+        }
+    }
+    impl Mul<Fp251> for EdPoint {
+        type Output = Self;
+        fn mul(self, rhs: Fp251) -> Self {
+            scalar_mul(self, &rhs)
+        }
+    }
+    impl<'a> Mul<&'a Fp251> for EdPoint {
+        type Output = Self;
+        fn mul(self, rhs: &'a Fp251) -> Self {
+            scalar_mul(self, rhs)
+        }
+    }
+    impl MulAssign<Fp251> for EdPoint {
+        fn mul_assign(&mut self, rhs: Fp251) {
+            *self = *self * rhs;
+        }
+    }
+    impl<'a> MulAssign<&'a Fp251> for EdPoint {
+        fn mul_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self * *rhs;
+        }
+    }
+
+    impl GroupEncoding for EdPoint {
+        type Repr = [u8; 2];
+
+        fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+            let x = Fp251::from_repr([bytes[0]]);
+            let y = Fp251::from_repr([bytes[1]]);
+            x.and_then(|x| y.and_then(|y| CtOption::new(EdPoint { x, y }, EdPoint::is_on_curve(x, y))))
+        }
+
+        fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+            Self::from_bytes(bytes)
+        }
+
+        fn to_bytes(&self) -> Self::Repr {
+            [self.x.to_repr()[0], self.y.to_repr()[0]]
+        }
+    }
+
+    impl Group for EdPoint {
+        type Scalar = Fp251;
+
+        fn random(rng: impl RngCore) -> Self {
+            <EdPoint as Group>::generator() * Fp251::random(rng)
+        }
+
+        fn identity() -> Self {
+            <Self as Identity>::identity()
+        }
+
+        fn generator() -> Self {
+            // (1, 0): a generic, non-identity point on the curve.
+            EdPoint { x: Fp251::ONE, y: Fp251::ZERO }
+        }
+
+        fn is_identity(&self) -> Choice {
+            self.x.ct_eq(&Fp251::ZERO) & self.y.ct_eq(&Fp251::ONE)
+        }
+
+        fn double(&self) -> Self {
+            *self + *self
+        }
+    }
+
+    impl Curve for EdPoint {
+        type AffineRepr = EdPoint;
+        type Scalar = Fp251;
+
+        fn to_affine(&self) -> Self::AffineRepr {
+            *self
+        }
+    }
+
+    impl CurveAffine for EdPoint {
+        type Scalar = Fp251;
+        type Curve = EdPoint;
+
+        fn generator() -> Self {
+            <Self as Group>::generator()
+        }
+
+        fn is_identity(&self) -> Choice {
+            <Self as Group>::is_identity(self)
+        }
+
+        fn to_curve(&self) -> Self::Curve {
+            *self
+        }
+    }
+
+    impl super::TwistedEdwardsPoint for EdPoint {
+        type Base = Fp251;
+
+        const A: Fp251 = Fp251(1);
+        const D: Fp251 = Fp251(2);
+        const Z: Fp251 = Fp251(2);
+
+        fn from_bare_coordinates(x: Fp251, y: Fp251) -> CtOption<Self> {
+            CtOption::new(EdPoint { x, y }, EdPoint::is_on_curve(x, y))
+        }
+
+        fn from_coordinates(coords: TwistedEdwardsCoordinates<Self>) -> Self {
+            EdPoint { x: coords.x(), y: coords.y() }
+        }
+
+        fn coordinates(&self) -> TwistedEdwardsCoordinates<Self> {
+            TwistedEdwardsCoordinates::from_coordinates(self.x, self.y).unwrap()
+        }
+    }
+
+    /// An affine point on the toy Montgomery curve $247v^2 = u^3 + 245u^2 + u$,
+    /// birationally equivalent to [`EdPoint`]'s curve.
+    #[derive(Clone, Copy, Debug)]
+    struct MontPoint {
+        u: Fp251,
+        v: Fp251,
+        infinity: Choice,
+    }
+
+    impl MontPoint {
+        fn is_on_curve(u: Fp251, v: Fp251) -> Choice {
+            let lhs = <MontPoint as super::MontgomeryPoint>::B * v.square();
+            let rhs = u.square() * u + <MontPoint as super::MontgomeryPoint>::A * u.square() + u;
+            lhs.ct_eq(&rhs)
+        }
+    }
+
+    impl Default for MontPoint {
+        fn default() -> Self {
+            <Self as Identity>::identity()
+        }
+    }
+
+    impl PartialEq for MontPoint {
+        fn eq(&self, other: &Self) -> bool {
+            bool::from(self.ct_eq(other))
+        }
+    }
+    impl Eq for MontPoint {}
+
+    impl ConstantTimeEq for MontPoint {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.infinity.ct_eq(&other.infinity)
+                & (self.infinity | (self.u.ct_eq(&other.u) & self.v.ct_eq(&other.v)))
+        }
+    }
+
+    impl ConditionallySelectable for MontPoint {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            MontPoint {
+                u: Fp251::conditional_select(&a.u, &b.u, choice),
+                v: Fp251::conditional_select(&a.v, &b.v, choice),
+                infinity: Choice::conditional_select(&a.infinity, &b.infinity, choice),
+            }
+        }
+    }
+
+    impl Identity for MontPoint {
+        fn identity() -> Self {
+            MontPoint { u: Fp251::ZERO, v: Fp251::ZERO, infinity: Choice::from(1) }
+        }
+    }
+
+    impl Neg for MontPoint {
+        type Output = Self;
+        fn neg(self) -> Self {
+            MontPoint { u: self.u, v: -self.v, infinity: self.infinity }
+        }
+    }
+
+    // `MontPoint` has no simple unified addition law, so its group operations are defined
+    // by routing through the birationally-equivalent `EdPoint`, whose addition law we
+    // already have. This is only ever used to satisfy `Group`/`Curve`'s trait bounds for
+    // this test fixture; the round-trip tests below never call it.
+    impl Add for MontPoint {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            let lhs: EdPoint = from_montgomery(&self);
+            let rhs: EdPoint = from_montgomery(&rhs);
+            to_montgomery(&(lhs + rhs))
+        }
+    }
+    impl Sub for MontPoint {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            self + (-rhs)
+        }
+    }
+    impl<'a> Add<&'a MontPoint> for MontPoint {
+        type Output = Self;
+        fn add(self, rhs: &'a MontPoint) -> Self {
+            self + *rhs
+        }
+    }
+    impl<'a> Sub<&'a MontPoint> for MontPoint {
+        type Output = Self;
+        fn sub(self, rhs: &'a MontPoint) -> Self {
+            self - *rhs
+        }
+    }
+    impl AddAssign for MontPoint {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl SubAssign for MontPoint {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl<'a> AddAssign<&'a MontPoint> for MontPoint {
+        fn add_assign(&mut self, rhs: &'a MontPoint) {
+            *self = *self + *rhs;
+        }
+    }
+    impl<'a> SubAssign<&'a MontPoint> for MontPoint {
+        fn sub_assign(&mut self, rhs: &'a MontPoint) {
+            *self = *self - *rhs;
+        }
+    }
+    impl Sum for MontPoint {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(<MontPoint as Identity>::identity(), |a, b| a + b)
+        }
+    }
+    impl<'a> Sum<&'a MontPoint> for MontPoint {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(<MontPoint as Identity>::identity(), |a, b| a + *b)
+        }
+    }
+    impl Mul<Fp251> for MontPoint {
+        type Output = Self;
+        fn mul(self, rhs: Fp251) -> Self {
+            scalar_mul(self, &rhs)
+        }
+    }
+    impl<'a> Mul<&'a Fp251> for MontPoint {
+        type Output = Self;
+        fn mul(self, rhs: &'a Fp251) -> Self {
+            scalar_mul(self, rhs)
+        }
+    }
+    impl MulAssign<Fp251> for MontPoint {
+        fn mul_assign(&mut self, rhs: Fp251) {
+            *self = *self * rhs;
+        }
+    }
+    impl<'a> MulAssign<&'a Fp251> for MontPoint {
+        fn mul_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self * *rhs;
+        }
+    }
+
+    impl GroupEncoding for MontPoint {
+        type Repr = [u8; 3];
+
+        fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+            let infinity = Choice::from(bytes[0]);
+            let u = Fp251::from_repr([bytes[1]]);
+            let v = Fp251::from_repr([bytes[2]]);
+            u.and_then(|u| {
+                v.and_then(|v| {
+                    let on_curve = MontPoint::is_on_curve(u, v) | infinity;
+                    CtOption::new(MontPoint { u, v, infinity }, on_curve)
+                })
+            })
+        }
+
+        fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+            Self::from_bytes(bytes)
+        }
+
+        fn to_bytes(&self) -> Self::Repr {
+            [self.infinity.unwrap_u8(), self.u.to_repr()[0], self.v.to_repr()[0]]
+        }
+    }
+
+    impl Group for MontPoint {
+        type Scalar = Fp251;
+
+        fn random(rng: impl RngCore) -> Self {
+            <MontPoint as Group>::generator() * Fp251::random(rng)
+        }
+
+        fn identity() -> Self {
+            <Self as Identity>::identity()
+        }
+
+        fn generator() -> Self {
+            // The image of `EdPoint::generator()` under `to_montgomery`: (1, 1).
+            MontPoint { u: Fp251::ONE, v: Fp251::ONE, infinity: Choice::from(0) }
+        }
+
+        fn is_identity(&self) -> Choice {
+            self.infinity
+        }
+
+        fn double(&self) -> Self {
+            *self + *self
+        }
+    }
+
+    impl Curve for MontPoint {
+        type AffineRepr = MontPoint;
+        type Scalar = Fp251;
+
+        fn to_affine(&self) -> Self::AffineRepr {
+            *self
+        }
+    }
+
+    impl CurveAffine for MontPoint {
+        type Scalar = Fp251;
+        type Curve = MontPoint;
+
+        fn generator() -> Self {
+            <Self as Group>::generator()
+        }
+
+        fn is_identity(&self) -> Choice {
+            <Self as Group>::is_identity(self)
+        }
+
+        fn to_curve(&self) -> Self::Curve {
+            *self
+        }
+    }
+
+    impl super::MontgomeryPoint for MontPoint {
+        type Base = Fp251;
+
+        const A: Fp251 = Fp251(245);
+        const B: Fp251 = Fp251(247);
+        const Z: Fp251 = Fp251(2);
+
+        fn from_bare_coordinates(u: Fp251, v: Fp251) -> CtOption<Self> {
+            CtOption::new(MontPoint { u, v, infinity: Choice::from(0) }, MontPoint::is_on_curve(u, v))
+        }
+
+        fn from_coordinates(coords: MontgomeryCoordinates<Self>) -> Self {
+            MontPoint { u: coords.u(), v: coords.v(), infinity: Choice::from(0) }
+        }
+
+        fn coordinates(&self) -> CtOption<MontgomeryCoordinates<Self>> {
+            CtOption::new(
+                MontgomeryCoordinates::from_coordinates(self.u, self.v).unwrap(),
+                !self.infinity,
+            )
+        }
+    }
+
+    #[test]
+    fn to_montgomery_round_trips_a_generic_point() {
+        // (1, 0) is a generic (non-identity, non-2-torsion) point on the Edwards curve.
+        let p = EdPoint { x: Fp251::ONE, y: Fp251::ZERO };
+        let m: MontPoint = to_montgomery(&p);
+
+        let coords = m.coordinates().unwrap();
+        assert_eq!(coords.u(), Fp251::ONE);
+        assert_eq!(coords.v(), Fp251::ONE);
+
+        let back: EdPoint = from_montgomery(&m);
+        assert_eq!(back, p);
+    }
+
+    #[test]
+    fn to_montgomery_maps_the_edwards_identity_to_the_montgomery_identity() {
+        let identity = <EdPoint as Identity>::identity();
+        let m: MontPoint = to_montgomery(&identity);
+        assert!(bool::from(<MontPoint as Group>::is_identity(&m)));
+
+        let back: EdPoint = from_montgomery(&m);
+        assert_eq!(back, identity);
+    }
+
+    #[test]
+    fn to_montgomery_round_trips_the_order_two_edwards_point() {
+        // (0, -1) is the Edwards curve's other 2-torsion point besides the identity. It
+        // is not special-cased by `to_montgomery` (only `y == 1` is), but happens to land
+        // on a valid finite Montgomery point, (0, 0) -- the `unwrap_or(ZERO)` fallback for
+        // the `x.invert()` division by zero coincides with a genuine point on the curve.
+        let p = EdPoint { x: Fp251::ZERO, y: -Fp251::ONE };
+        let m: MontPoint = to_montgomery(&p);
+        assert!(!bool::from(<MontPoint as Group>::is_identity(&m)));
+
+        let coords = m.coordinates().unwrap();
+        assert_eq!(coords.u(), Fp251::ZERO);
+        assert_eq!(coords.v(), Fp251::ZERO);
+
+        let back: EdPoint = from_montgomery(&m);
+        assert_eq!(back, p);
+    }
+}
@@ -0,0 +1,76 @@
+use ff::PrimeField;
+use subtle::{Choice, ConditionallySelectable};
+
+use crate::prime::PrimeCurve;
+use crate::Group;
+
+/// A [`PrimeCurve`] equipped with an efficiently-computable endomorphism $\phi$, enabling
+/// the GLV method for scalar multiplication.
+///
+/// Curves implement this when there is a nontrivial automorphism $\phi$ of the curve
+/// group such that $\phi(P) = [\lambda]P$ for some fixed scalar $\lambda$ (a cube root of
+/// unity modulo the subgroup order $r$), computable directly on coordinates (for a short
+/// Weierstrass curve with $a = 0$, $\phi(x, y) = (\beta x, y)$ for a cube root of unity
+/// $\beta$ in the base field).
+pub trait GlvEndomorphism: PrimeCurve + ConditionallySelectable {
+    /// The cube root of unity $\lambda$ in the scalar field, such that
+    /// $\phi(P) = [\lambda]P$ for every point $P$.
+    const LAMBDA: Self::Scalar;
+
+    /// Applies the curve endomorphism $\phi$ to `self`.
+    ///
+    /// For every point $P$, `P.endomorphism() == P * Self::LAMBDA`, but computing the
+    /// left-hand side is expected to be substantially cheaper than the scalar
+    /// multiplication on the right.
+    fn endomorphism(&self) -> Self;
+
+    /// Decomposes a scalar $k$ into a balanced-length GLV decomposition
+    /// $k \equiv k_1 + k_2 \lambda \pmod r$ with $|k_1|, |k_2| \approx \sqrt{r}$.
+    ///
+    /// Implementations compute this using the precomputed short basis
+    /// $(a_1, b_1), (a_2, b_2)$ of the lattice $\{(x, y) : x + y\lambda \equiv 0
+    /// \pmod r\}$: with $c_1 = \mathrm{round}(b_2 k / r)$ and
+    /// $c_2 = \mathrm{round}(-b_1 k / r)$, the decomposition is
+    /// $k_1 = k - c_1 a_1 - c_2 a_2$, $k_2 = -c_1 b_1 - c_2 b_2$.
+    ///
+    /// Returns `(|k1|, |k2|, k1_is_negative, k2_is_negative)`: the absolute values of the
+    /// (possibly negative, as integers) balanced decomposition, each reduced back into
+    /// [`Self::Scalar`], together with flags recording whether the corresponding half was
+    /// negated to make it non-negative.
+    fn decompose_scalar(k: &Self::Scalar) -> (Self::Scalar, Self::Scalar, Choice, Choice);
+
+    /// Computes `self * k` using the GLV method.
+    ///
+    /// `k` is decomposed into `k1 + k2 * Self::LAMBDA`, and the result is computed as an
+    /// interleaved double-and-add over `self` and `self.endomorphism()` simultaneously.
+    /// Since the balanced decomposition guarantees `|k1|, |k2| ≈ sqrt(r)`, only about
+    /// half as many bits need to be scanned as a naive scalar multiplication by `k` would
+    /// require, at the cost of an extra point addition per bit.
+    fn mul_glv(&self, k: &Self::Scalar) -> Self {
+        let (k1, k2, k1_neg, k2_neg) = Self::decompose_scalar(k);
+
+        let p1 = Self::conditional_select(self, &-*self, k1_neg);
+        let phi_p = self.endomorphism();
+        let p2 = Self::conditional_select(&phi_p, &-phi_p, k2_neg);
+
+        let k1_repr = k1.to_repr();
+        let k2_repr = k2.to_repr();
+        let k1_bytes = k1_repr.as_ref();
+        let k2_bytes = k2_repr.as_ref();
+
+        // |k1|, |k2| are bounded by roughly sqrt(r), i.e. about half the bit-length of
+        // the subgroup order; scan only that many bits (plus one for rounding slack)
+        // rather than the full width of `Self::Scalar`'s canonical encoding.
+        let bit_length = (Self::Scalar::NUM_BITS as usize).div_ceil(2) + 1;
+
+        let mut acc = Self::identity();
+        for i in (0..bit_length).rev() {
+            acc = acc.double();
+            let bit1 = Choice::from((k1_bytes[i / 8] >> (i % 8)) & 1);
+            let bit2 = Choice::from((k2_bytes[i / 8] >> (i % 8)) & 1);
+            acc = Self::conditional_select(&acc, &(acc + p1), bit1);
+            acc = Self::conditional_select(&acc, &(acc + p2), bit2);
+        }
+        acc
+    }
+}
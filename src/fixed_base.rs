@@ -0,0 +1,115 @@
+use ff::PrimeField;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::prime::PrimeCurveAffine;
+use crate::Group;
+
+/// Window width, in bits, used by the comb/windowed fixed-base algorithm of
+/// [`MulByGenerator`] and [`ComputedGeneratorTable`].
+///
+/// Four bits was chosen so that each window aligns with a nibble of the scalar's
+/// byte-oriented canonical encoding.
+pub const WINDOW_BITS: usize = 4;
+
+/// A precomputed table of small multiples of a [`PrimeCurveAffine`] generator, indexed by
+/// window position, used to accelerate repeated `[k]G`-style computations.
+///
+/// For window `i` and nonzero digit `d` (`1..16`), the table conceptually holds
+/// $d \cdot 2^{4i} \cdot G$; digit `0` always contributes the identity and need not be
+/// stored. Implementors are expected to perform the lookup via a constant-time linear
+/// scan (using [`ConditionallySelectable`]) so that the accessed table index does not
+/// leak through timing.
+pub trait GeneratorTable<C: PrimeCurveAffine> {
+    /// Returns $d \cdot 2^{4 \cdot \text{window}} \cdot G$ for `digit` $d$ in `0..16`.
+    fn lookup(&self, window: usize, digit: u8) -> C::Curve;
+}
+
+/// A curve whose [`PrimeCurveAffine::generator`] has an associated fixed-base
+/// precomputation table, making repeated `[k] * generator()`-style computations (as seen
+/// in e.g. public-key derivation) substantially cheaper than the generic
+/// `generator() * k`, *provided the table is built once and reused across calls*.
+///
+/// [`Self::precompute`] and [`Self::mul_by_generator`] are deliberately separate so that
+/// callers performing many multiplications can amortize the cost of building the table:
+/// for [`ComputedGeneratorTable`] in particular, `precompute()` itself costs several times
+/// as much as a naive scalar multiplication, so calling it once per [`Self::mul_by_generator`]
+/// would defeat the point of this trait entirely. Callers should call [`Self::precompute`]
+/// once (or use a curve-provided hardcoded table) and pass the same table to every
+/// [`Self::mul_by_generator`] call that follows.
+pub trait MulByGenerator: PrimeCurveAffine {
+    /// The table type returned by [`Self::precompute`].
+    ///
+    /// Curves without a convenient closed form for the generator's multiples can use
+    /// [`ComputedGeneratorTable`], which builds the table at runtime from the generic
+    /// group operations; curves that ship a hardcoded table (e.g. as a `static`) should
+    /// implement [`GeneratorTable`] directly on their own type instead.
+    type Table: GeneratorTable<Self>;
+
+    /// Builds (or returns a cached/hardcoded) precomputed table for [`Self::generator`].
+    ///
+    /// This is the expensive step: for [`ComputedGeneratorTable`] it costs several times as
+    /// much as a naive scalar multiplication. Build the table once and reuse it across many
+    /// [`Self::mul_by_generator`] calls to amortize this cost.
+    fn precompute() -> Self::Table;
+
+    /// Computes `Self::generator() * scalar` using a fixed-base `table` previously
+    /// returned by [`Self::precompute`].
+    ///
+    /// The scalar is scanned in `WINDOW_BITS`-bit windows; the table entry for each
+    /// window is looked up and accumulated, with no point doublings required since each
+    /// table entry already accounts for its window's positional weight.
+    fn mul_by_generator(table: &Self::Table, scalar: &Self::Scalar) -> Self::Curve {
+        let repr = scalar.to_repr();
+        let bytes = repr.as_ref();
+
+        let mut acc = Self::Curve::identity();
+        for window in (0..bytes.len() * 2).rev() {
+            let byte = bytes[window / 2];
+            let digit = if window % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+            acc = acc + table.lookup(window, digit);
+        }
+        acc
+    }
+}
+
+/// A [`GeneratorTable`] built at runtime from the generic group operations, for curves
+/// that do not ship a hardcoded table.
+pub struct ComputedGeneratorTable<C: PrimeCurveAffine> {
+    /// `entries[window * 15 + (digit - 1)]` holds $d \cdot 2^{4 \cdot \text{window}} \cdot G$.
+    entries: Vec<C::Curve>,
+}
+
+impl<C: PrimeCurveAffine> ComputedGeneratorTable<C> {
+    /// Builds the table for `generator`, covering every byte of `C::Scalar`'s canonical
+    /// encoding.
+    pub fn build(generator: C) -> Self {
+        let window_count = C::Scalar::default().to_repr().as_ref().len() * 2;
+
+        let mut entries = Vec::with_capacity(window_count * 15);
+        let mut window_base = generator.to_curve();
+        for _ in 0..window_count {
+            let mut digit_multiple = C::Curve::identity();
+            for _ in 0..15 {
+                digit_multiple = digit_multiple + window_base;
+                entries.push(digit_multiple);
+            }
+            // Advance to the next window's base: multiply by 2^WINDOW_BITS.
+            for _ in 0..WINDOW_BITS {
+                window_base = window_base.double();
+            }
+        }
+
+        ComputedGeneratorTable { entries }
+    }
+}
+
+impl<C: PrimeCurveAffine> GeneratorTable<C> for ComputedGeneratorTable<C> {
+    fn lookup(&self, window: usize, digit: u8) -> C::Curve {
+        let mut result = C::Curve::identity();
+        for (i, entry) in self.entries[window * 15..window * 15 + 15].iter().enumerate() {
+            let is_digit = (i as u8 + 1).ct_eq(&digit);
+            result = C::Curve::conditional_select(&result, entry, is_digit);
+        }
+        result
+    }
+}
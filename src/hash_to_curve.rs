@@ -0,0 +1,508 @@
+//! Generic support for hashing arbitrary byte strings to group elements, following the
+//! framework described in [RFC 9380].
+//!
+//! The construction is split into the same stages as the RFC:
+//!
+//! 1. `hash_to_field` expands the input message into `COUNT` base-field elements using
+//!    [`expand_message_xmd`]: `COUNT = 2` for the random-oracle `hash_to_curve` encoding,
+//!    `COUNT = 1` for the non-uniform `encode_to_curve` encoding.
+//! 2. A model-specific `map_to_curve` method (defined on the
+//!    [`ShortWeierstrassPoint`](crate::coordinates::ShortWeierstrassPoint),
+//!    [`MontgomeryPoint`](crate::coordinates::MontgomeryPoint) and
+//!    [`TwistedEdwardsPoint`](crate::coordinates::TwistedEdwardsPoint) coordinate traits)
+//!    converts each field element to a point on the curve.
+//! 3. For `hash_to_curve`, the resulting points are added together; `encode_to_curve`
+//!    skips this step, since it only produces one point.
+//! 4. [`CofactorGroup::clear_cofactor`] is applied to land in the prime-order subgroup.
+//!
+//! Curve implementations wire these stages together by implementing [`HashToCurve`];
+//! this module provides the shared, model-specific plumbing (steps 1 and 2) so that the
+//! per-curve impl only needs to drive the stages in order, e.g.:
+//!
+//! ```ignore
+//! impl HashToCurve for FooPoint {
+//!     const CURVE_ID: &'static str = "foo_XMD:SHA-256_SSWU_RO_";
+//!
+//!     fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self::Subgroup + 'a> {
+//!         Box::new(move |message| {
+//!             let dst = [domain_prefix.as_bytes(), Self::CURVE_ID.as_bytes()].concat();
+//!             let [u0, u1] = hash_to_field::<FooBase, 64, 2>(message, &dst);
+//!             let p0 = FooAffine::map_to_curve(u0).to_curve();
+//!             let p1 = FooAffine::map_to_curve(u1).to_curve();
+//!             (p0 + p1).clear_cofactor()
+//!         })
+//!     }
+//!
+//!     fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self::Subgroup + 'a> {
+//!         Box::new(move |message| {
+//!             let dst = [domain_prefix.as_bytes(), Self::CURVE_ID.as_bytes()].concat();
+//!             let [u0] = hash_to_field::<FooBase, 64, 1>(message, &dst);
+//!             FooAffine::map_to_curve(u0).to_curve().clear_cofactor()
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+
+use ff::{Field, PrimeField};
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::cofactor::CofactorGroup;
+
+/// A base field that can be sampled uniformly at random from a wide byte string, as
+/// required by the `hash_to_field` step of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+///
+/// `N` is the number of input bytes, which must be chosen large enough (at least
+/// `ceil((ceil(log2(p)) + k) / 8)` for a target security level of `k` bits) that the
+/// reduction modulo the field's order is statistically close to uniform.
+pub trait FromUniformBytes<const N: usize>: Field {
+    /// Interprets `bytes` as the base-256 encoding of an integer and reduces it modulo
+    /// the field's order.
+    fn from_uniform_bytes(bytes: &[u8; N]) -> Self;
+}
+
+/// Types that can hash arbitrary byte strings to uniformly-random elements of the
+/// prime-order subgroup, following the construction described in [RFC 9380].
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+pub trait HashToCurve: CofactorGroup {
+    /// A short identifier for this curve and its chosen suite (hash function, mapping,
+    /// and encoding variant), used together with a caller-supplied domain prefix to build
+    /// the domain-separation tag required by RFC 9380.
+    const CURVE_ID: &'static str;
+
+    /// Returns a function that hashes an arbitrary byte string to a uniformly-random
+    /// element of the prime-order subgroup.
+    ///
+    /// `domain_prefix` identifies the protocol invoking this function, and is combined
+    /// with [`Self::CURVE_ID`] to build the domain-separation tag.
+    fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self::Subgroup + 'a>;
+
+    /// Returns a function that encodes an arbitrary byte string to an element of the
+    /// prime-order subgroup.
+    ///
+    /// Unlike [`Self::hash_to_curve`], the output is *not* uniformly distributed, so
+    /// `encode_to_curve` must only be used where this is acceptable (for example, when
+    /// the output is immediately combined with other values and never observed on its
+    /// own by an adversary).
+    fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self::Subgroup + 'a>;
+}
+
+/// Expands `msg` into `output.len()` pseudorandom bytes, using the `expand_message_xmd`
+/// construction of [RFC 9380 §5.3.1] instantiated with SHA-256.
+///
+/// [RFC 9380 §5.3.1]: https://www.rfc-editor.org/rfc/rfc9380#section-5.3.1
+fn expand_message_xmd(msg: &[u8], dst: &[u8], output: &mut [u8]) {
+    const B_IN_BYTES: usize = 32; // SHA-256 output size.
+    const S_IN_BYTES: usize = 64; // SHA-256 block size.
+
+    let len_in_bytes = output.len();
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255, "requested output too long for expand_message_xmd");
+    assert!(dst.len() <= 255, "DST too long");
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let z_pad = [0u8; S_IN_BYTES];
+    let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+    let b_0 = Sha256::new()
+        .chain_update(z_pad)
+        .chain_update(msg)
+        .chain_update(l_i_b_str)
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut b_prev = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut offset = 0;
+    for i in 2..=(ell as u8) {
+        let copy_len = core::cmp::min(B_IN_BYTES, len_in_bytes - offset);
+        output[offset..offset + copy_len].copy_from_slice(&b_prev[..copy_len]);
+        offset += copy_len;
+
+        let mut xored = [0u8; B_IN_BYTES];
+        for (x, (a, b)) in xored.iter_mut().zip(b_0.iter().zip(b_prev.iter())) {
+            *x = a ^ b;
+        }
+        b_prev = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i])
+            .chain_update(&dst_prime)
+            .finalize();
+    }
+    let copy_len = len_in_bytes - offset;
+    output[offset..offset + copy_len].copy_from_slice(&b_prev[..copy_len]);
+}
+
+/// Implements the `hash_to_field` step of [RFC 9380 §5.2], producing the `COUNT`
+/// base-field elements consumed by `COUNT` calls to `map_to_curve`.
+///
+/// `COUNT` is `2` for the random-oracle `hash_to_curve` encoding and `1` for the
+/// non-uniform `encode_to_curve` encoding; `N` is [`FromUniformBytes`]'s uniform-bytes
+/// width for `F`. Note that `hash_to_field::<F, N, 1>` is a genuinely different hash from
+/// (not a truncation of) `hash_to_field::<F, N, 2>`, since the expanded output length —
+/// and hence the domain-separated `expand_message_xmd` call underneath — depends on
+/// `COUNT`.
+///
+/// [RFC 9380 §5.2]: https://www.rfc-editor.org/rfc/rfc9380#section-5.2
+pub fn hash_to_field<F: FromUniformBytes<N>, const N: usize, const COUNT: usize>(
+    msg: &[u8],
+    dst: &[u8],
+) -> [F; COUNT] {
+    let mut expanded = vec![0u8; N * COUNT];
+    expand_message_xmd(msg, dst, &mut expanded);
+
+    core::array::from_fn(|i| {
+        let mut u_i = [0u8; N];
+        u_i.copy_from_slice(&expanded[i * N..(i + 1) * N]);
+        F::from_uniform_bytes(&u_i)
+    })
+}
+
+/// Computes the constant-time `sqrt_ratio` helper of [RFC 9380 §4.1], given a
+/// known non-square element `z` of the field.
+///
+/// Returns `(true, sqrt(num / den))` if `num / den` is a square, and otherwise
+/// `(false, sqrt(Z * num / den))`.
+fn sqrt_ratio<F: Field>(num: F, den: F, z: F) -> (Choice, F) {
+    let ratio = num * den.invert().unwrap_or(F::ZERO);
+    let sqrt_ratio = ratio.sqrt();
+    let is_square = sqrt_ratio.is_some();
+    let sqrt_alt = (z * ratio).sqrt();
+    let root = F::conditional_select(
+        &sqrt_alt.unwrap_or(F::ZERO),
+        &sqrt_ratio.unwrap_or(F::ZERO),
+        is_square,
+    );
+    (is_square, root)
+}
+
+/// Returns the sign of `x`: the least-significant bit of its canonical little-endian
+/// encoding, used by the maps below to make a constant-time choice between `y` and `-y`
+/// (RFC 9380 §4.1's `sgn0` for prime fields).
+fn sgn0<F: PrimeField>(x: F) -> Choice {
+    Choice::from(x.to_repr().as_ref()[0] & 1)
+}
+
+/// Maps a base-field element to a point on a short Weierstrass curve $y^2 = x^3 + ax + b$
+/// using the Simplified SWU method of [RFC 9380 §6.6.2], valid whenever $a \cdot b \neq 0$.
+///
+/// Returns the affine $(x, y)$ coordinates of the resulting point, which is not
+/// necessarily in the prime-order subgroup.
+///
+/// [RFC 9380 §6.6.2]: https://www.rfc-editor.org/rfc/rfc9380#section-6.6.2
+pub fn map_to_curve_simple_swu<F: PrimeField>(u: F, a: F, b: F, z: F) -> (F, F) {
+    let tv1 = z * u.square();
+    let tv2 = tv1.square() + tv1;
+    let tv3 = tv2 + F::ONE;
+    let tv3 = b * tv3;
+    let tv4 = F::conditional_select(&(-tv2), &z, tv2.is_zero());
+    let tv4 = a * tv4;
+    let tv2sq = tv3.square();
+    let tv6 = tv4.square();
+    let tv5 = a * tv6;
+    let tv2sq = tv2sq + tv5;
+    let tv2sq = tv2sq * tv3;
+    let tv6 = tv6 * tv4;
+    let tv5 = b * tv6;
+    let gx1 = tv2sq + tv5;
+    let x1 = tv1 * tv3;
+
+    let (is_gx1_square, y1) = sqrt_ratio(gx1, tv6, z);
+
+    let x = F::conditional_select(&x1, &tv3, is_gx1_square);
+    let y = F::conditional_select(&(tv1 * u * y1), &y1, is_gx1_square);
+
+    let e1 = sgn0(u).ct_eq(&sgn0(y));
+    let y = F::conditional_select(&(-y), &y, e1);
+    let x = x * tv4.invert().unwrap_or(F::ZERO);
+
+    (x, y)
+}
+
+/// Maps a base-field element to a point on a Montgomery curve $Bv^2 = u^3 + Au^2 + u$
+/// using the Elligator 2 method of [RFC 9380 §6.7.1].
+///
+/// Returns the affine $(u, v)$ coordinates of the resulting point, which is not
+/// necessarily in the prime-order subgroup.
+///
+/// [RFC 9380 §6.7.1]: https://www.rfc-editor.org/rfc/rfc9380#section-6.7.1
+pub fn map_to_curve_elligator2<F: PrimeField>(field_u: F, a: F, b: F, z: F) -> (F, F) {
+    // The construction below targets the curve $y^2 = x^3 + (A/B)x^2 + x/B^2$; scaling
+    // $(x, y)$ by $B$ at the end lands on $Bv^2 = u^3 + Au^2 + u$ instead.
+    let inv_b = b.invert().unwrap_or(F::ZERO);
+    let c1 = a * inv_b;
+    let c2 = inv_b.square();
+
+    let tv1 = z * field_u.square();
+    let e1 = tv1.ct_eq(&(-F::ONE));
+    let tv1 = F::conditional_select(&tv1, &F::ZERO, e1);
+    let x1 = tv1 + F::ONE;
+    let x1 = (-c1) * x1.invert().unwrap_or(F::ZERO);
+
+    let gx1 = (x1 + c1) * x1 + c2;
+    let gx1 = gx1 * x1;
+    let x2 = -x1 - c1;
+    let gx2 = tv1 * gx1;
+
+    let e2 = gx1.sqrt().is_some();
+    let x = F::conditional_select(&x2, &x1, e2);
+    let y2 = F::conditional_select(&gx2, &gx1, e2);
+    let y = y2.sqrt().unwrap_or(F::ZERO);
+
+    let e3 = sgn0(y);
+    let y = F::conditional_select(&y, &(-y), e2 ^ e3);
+
+    (x * b, y * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::iter::{Product, Sum};
+    use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    use rand_core::RngCore;
+    use subtle::{ConstantTimeEq, CtOption};
+
+    use super::*;
+
+    /// Known-answer vectors for [`expand_message_xmd`] instantiated with SHA-256, taken
+    /// from [RFC 9380 Appendix K.1], DST `QUUX-V01-CS02-with-expander-SHA256-128`,
+    /// `len_in_bytes = 0x20`.
+    ///
+    /// [RFC 9380 Appendix K.1]: https://www.rfc-editor.org/rfc/rfc9380#appendix-K.1
+    #[test]
+    fn expand_message_xmd_matches_rfc9380_vectors() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        let cases: &[(&[u8], [u8; 32])] = &[
+            (
+                b"",
+                [
+                    0x68, 0xa9, 0x85, 0xb8, 0x7e, 0xb6, 0xb4, 0x69, 0x52, 0x12, 0x89, 0x11, 0xf2,
+                    0xa4, 0x41, 0x2b, 0xbc, 0x30, 0x2a, 0x9d, 0x75, 0x96, 0x67, 0xf8, 0x7f, 0x7a,
+                    0x21, 0xd8, 0x03, 0xf0, 0x72, 0x35,
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    0xd8, 0xcc, 0xab, 0x23, 0xb5, 0x98, 0x5c, 0xce, 0xa8, 0x65, 0xc6, 0xc9, 0x7b,
+                    0x6e, 0x5b, 0x83, 0x50, 0xe7, 0x94, 0xe6, 0x03, 0xb4, 0xb9, 0x79, 0x02, 0xf5,
+                    0x3a, 0x8a, 0x0d, 0x60, 0x56, 0x15,
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    0xef, 0xf3, 0x14, 0x87, 0xc7, 0x70, 0xa8, 0x93, 0xcf, 0xb3, 0x6f, 0x91, 0x2f,
+                    0xbf, 0xcb, 0xff, 0x40, 0xd5, 0x66, 0x17, 0x71, 0xca, 0x4b, 0x2c, 0xb4, 0xea,
+                    0xfe, 0x52, 0x43, 0x33, 0xf5, 0xc1,
+                ],
+            ),
+        ];
+
+        for (msg, expected) in cases {
+            let mut output = [0u8; 32];
+            expand_message_xmd(msg, DST, &mut output);
+            assert_eq!(&output, expected);
+        }
+    }
+
+    const MODULUS: u32 = 251;
+
+    fn mod_pow(mut base: u32, mut exp: u32) -> u32 {
+        base %= MODULUS;
+        let mut result = 1u32;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % MODULUS;
+            }
+            exp >>= 1;
+            base = base * base % MODULUS;
+        }
+        result
+    }
+
+    /// A toy prime field of order 251, used only to exercise [`hash_to_field`] against a
+    /// concrete [`FromUniformBytes`] implementation.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Fp251(u8);
+
+    impl Fp251 {
+        fn new(v: u32) -> Self {
+            Fp251((v % MODULUS) as u8)
+        }
+    }
+
+    impl ConstantTimeEq for Fp251 {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl ConditionallySelectable for Fp251 {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            Fp251(u8::conditional_select(&a.0, &b.0, choice))
+        }
+    }
+
+    impl Add for Fp251 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 + rhs.0 as u32)
+        }
+    }
+    impl Sub for Fp251 {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 + MODULUS - rhs.0 as u32)
+        }
+    }
+    impl Mul for Fp251 {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Fp251::new(self.0 as u32 * rhs.0 as u32)
+        }
+    }
+    impl Neg for Fp251 {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Fp251::new(MODULUS - self.0 as u32)
+        }
+    }
+    impl<'a> Add<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn add(self, rhs: &'a Fp251) -> Self {
+            self + *rhs
+        }
+    }
+    impl<'a> Sub<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn sub(self, rhs: &'a Fp251) -> Self {
+            self - *rhs
+        }
+    }
+    impl<'a> Mul<&'a Fp251> for Fp251 {
+        type Output = Self;
+        fn mul(self, rhs: &'a Fp251) -> Self {
+            self * *rhs
+        }
+    }
+    impl AddAssign for Fp251 {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl SubAssign for Fp251 {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl MulAssign for Fp251 {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+    impl<'a> AddAssign<&'a Fp251> for Fp251 {
+        fn add_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self + *rhs;
+        }
+    }
+    impl<'a> SubAssign<&'a Fp251> for Fp251 {
+        fn sub_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self - *rhs;
+        }
+    }
+    impl<'a> MulAssign<&'a Fp251> for Fp251 {
+        fn mul_assign(&mut self, rhs: &'a Fp251) {
+            *self = *self * *rhs;
+        }
+    }
+    impl Sum for Fp251 {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ZERO, |a, b| a + b)
+        }
+    }
+    impl<'a> Sum<&'a Fp251> for Fp251 {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ZERO, |a, b| a + *b)
+        }
+    }
+    impl Product for Fp251 {
+        fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ONE, |a, b| a * b)
+        }
+    }
+    impl<'a> Product<&'a Fp251> for Fp251 {
+        fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.fold(Fp251::ONE, |a, b| a * *b)
+        }
+    }
+
+    impl Field for Fp251 {
+        const ZERO: Self = Fp251(0);
+        const ONE: Self = Fp251(1);
+
+        fn random(mut rng: impl RngCore) -> Self {
+            Fp251::new(rng.next_u32())
+        }
+
+        fn square(&self) -> Self {
+            *self * *self
+        }
+
+        fn double(&self) -> Self {
+            *self + *self
+        }
+
+        fn invert(&self) -> CtOption<Self> {
+            let is_zero = self.ct_eq(&Fp251::ZERO);
+            let inv = Fp251::new(mod_pow(self.0 as u32, MODULUS - 2));
+            CtOption::new(inv, !is_zero)
+        }
+
+        fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+            let ratio = *num * div.invert().unwrap_or(Fp251::ZERO);
+            // 251 = 3 (mod 4), so sqrt(a) = a^((p+1)/4) whenever `a` is a square.
+            let candidate = Fp251::new(mod_pow(ratio.0 as u32, (MODULUS + 1) / 4));
+            let is_square = candidate.square().ct_eq(&ratio);
+            (is_square, candidate)
+        }
+    }
+
+    impl FromUniformBytes<4> for Fp251 {
+        fn from_uniform_bytes(bytes: &[u8; 4]) -> Self {
+            Fp251::new(u32::from_be_bytes(*bytes))
+        }
+    }
+
+    /// `hash_to_field` chunks the [`expand_message_xmd`] output into `N`-byte pieces and
+    /// reduces each one modulo the field's order, so its output is pinned down by the
+    /// `expand_message_xmd` vectors above together with [`FromUniformBytes::from_uniform_bytes`];
+    /// there is no official RFC 9380 KAT for a field as small as this one, so this test
+    /// checks internal consistency instead: determinism, and that `COUNT` participates in
+    /// domain separation as documented on [`hash_to_field`].
+    #[test]
+    fn hash_to_field_is_deterministic_and_domain_separates_on_count() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        let a = hash_to_field::<Fp251, 4, 2>(b"abc", DST);
+        let b = hash_to_field::<Fp251, 4, 2>(b"abc", DST);
+        assert_eq!(a, b);
+        assert_eq!(a, [Fp251::new(242), Fp251::new(211)]);
+
+        // `COUNT = 1` expands to a different length, hence a genuinely different
+        // `expand_message_xmd` output -- not merely a truncation of the `COUNT = 2` case.
+        let [c0] = hash_to_field::<Fp251, 4, 1>(b"abc", DST);
+        assert_eq!(c0, Fp251::new(28));
+        assert_ne!(c0, a[0]);
+    }
+}
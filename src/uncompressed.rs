@@ -0,0 +1,31 @@
+use subtle::CtOption;
+
+/// Affine elliptic curve points that can be serialized to and deserialized from a
+/// canonical, fixed-size, *uncompressed* byte representation.
+///
+/// This is distinct from [`GroupEncoding`](crate::GroupEncoding): many curves (BLS12-381
+/// being the canonical example) define both a compact compressed encoding and a larger
+/// uncompressed encoding that omits the coordinate-recovery step in exchange for faster
+/// (de)serialization, which is useful to e.g. pairing-based protocols that serialize and
+/// deserialize points on the hot path. This trait is orthogonal to [`GroupEncoding`](
+/// crate::GroupEncoding): curves that only define a compressed encoding simply do not
+/// implement it.
+pub trait UncompressedEncoding: Sized {
+    /// The uncompressed byte representation.
+    type Uncompressed: Copy + Send + Sync + 'static + AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    /// Deserializes this element from its uncompressed representation, failing if the
+    /// bytes do not encode a valid point in the correct subgroup.
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self>;
+
+    /// Deserializes this element from its uncompressed representation, *without*
+    /// checking that it represents a valid point in the correct subgroup.
+    ///
+    /// This is intended for trusted inputs (for example, values that have already been
+    /// validated, or that are read back from a trusted store) where skipping the checks
+    /// performed by [`Self::from_uncompressed`] meaningfully improves performance.
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self>;
+
+    /// Serializes this element into its uncompressed representation.
+    fn to_uncompressed(&self) -> Self::Uncompressed;
+}